@@ -25,6 +25,28 @@ https://www.khronos.org/registry/spir-v/specs/unified1/GLSL.std.450.html";
 static OPENCL_STD_SPEC_LINK: &'static str = "\
 https://www.khronos.org/registry/spir-v/specs/unified1/OpenCL.ExtendedInstructionSet.100.html";
 
+/// Opcodes (without the "Op" prefix) that the spec permits as the wrapped
+/// operation of an `OpSpecConstantOp` instruction. This set isn't derivable
+/// from the grammar fields, so it's curated here from the SPIR-V spec's
+/// "Specialization" section.
+static SPEC_CONSTANT_OP_WHITELIST: &[&str] = &[
+    "SConvert", "FConvert", "SNegate", "Not",
+    "IAdd", "ISub", "IMul", "UDiv", "SDiv", "UMod", "SRem", "SMod",
+    "ShiftRightLogical", "ShiftRightArithmetic", "ShiftLeftLogical",
+    "BitwiseOr", "BitwiseXor", "BitwiseAnd",
+    "VectorShuffle", "CompositeExtract", "CompositeInsert",
+    "LogicalOr", "LogicalAnd", "LogicalNot",
+    "LogicalEqual", "LogicalNotEqual",
+    "Select",
+    "IEqual", "INotEqual",
+    "ULessThan", "SLessThan", "UGreaterThan", "SGreaterThan",
+    "ULessThanEqual", "SLessThanEqual", "UGreaterThanEqual", "SGreaterThanEqual",
+    "QuantizeToF16",
+    "ConvertFToS", "ConvertSToF", "ConvertFToU", "ConvertUToF", "UConvert", "Bitcast",
+    "AccessChain", "InBoundsAccessChain", "PtrAccessChain", "InBoundsPtrAccessChain",
+    "GenericCastToPtr", "PtrCastToGeneric",
+];
+
 /// Returns the markdown string containing a link to the spec for the given
 /// operand `kind`.
 fn get_spec_link(kind: &str) -> String {
@@ -36,6 +58,21 @@ fn get_spec_link(kind: &str) -> String {
                            symbol, symbol))
 }
 
+/// Parses a SPIR-V grammar version string of the form `"x.y"` into a
+/// `(major, minor)` pair. A missing field or the literal string `"None"`
+/// (used by the grammar for enumerants/instructions that are not tied to a
+/// particular core version) both map to `None`.
+fn parse_version(version: Option<&String>) -> Option<(u8, u8)> {
+    let version = version?;
+    if version == "None" {
+        return None;
+    }
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
 fn value_enum_attribute() -> TokenStream {
     quote! {
         #[repr(u32)]
@@ -44,6 +81,8 @@ fn value_enum_attribute() -> TokenStream {
 }
 
 fn gen_bit_enum_operand_kind(grammar: &structs::OperandKind) -> TokenStream {
+    use std::collections::BTreeMap;
+
     let elements = grammar.enumerants.iter().map(|enumerant| {
         // Special treatment for "NaN"
         let symbol = as_ident(&enumerant.symbol.to_shouty_snake_case().replace("NA_N", "NAN"));
@@ -54,6 +93,76 @@ fn gen_bit_enum_operand_kind(grammar: &structs::OperandKind) -> TokenStream {
     });
     let comment = format!("SPIR-V operand kind: {}", get_spec_link(&grammar.kind));
     let kind = as_ident(&grammar.kind);
+
+    // Named bits (excluding the all-zero enumerant), in grammar definition
+    // order. Single collection pass: used for `Display`/`FromStr` (the
+    // symbol) as well as grouping the per-bit capability/extension/
+    // parameter tables below.
+    let named_bits: Vec<_> = grammar.enumerants.iter()
+        .filter(|e| e.value != 0)
+        .map(|e| {
+            let name = as_ident(&e.symbol.to_shouty_snake_case().replace("NA_N", "NAN"));
+            (name, e)
+        })
+        .collect();
+    let none_symbol = grammar.enumerants.iter()
+        .find(|e| e.value == 0)
+        .map(|e| e.symbol.clone())
+        .unwrap_or_else(|| "None".to_string());
+
+    let display_checks = named_bits.iter().map(|(name, e)| {
+        let symbol = &e.symbol;
+        quote! {
+            if self.contains(#kind::#name) {
+                if !first {
+                    write!(f, "|")?;
+                }
+                write!(f, "{}", #symbol)?;
+                first = false;
+            }
+        }
+    });
+    let from_str_arms = named_bits.iter().map(|(name, e)| {
+        let symbol = &e.symbol;
+        quote! { #symbol => result |= #kind::#name, }
+    });
+
+    // Static per-bit capability/extension/parameter tables, grouped the same
+    // way `capability_clauses` groups `required_capabilities()` for value
+    // enums. `required_capabilities()`/`required_extensions()`/`parameters()`
+    // then just union these static tables over the bits that are set,
+    // instead of rebuilding the per-bit data on every call.
+    let mut capability_clauses = BTreeMap::new();
+    let mut extension_clauses = BTreeMap::new();
+    let mut parameter_clauses = BTreeMap::new();
+    for (name, e) in &named_bits {
+        capability_clauses.entry(&e.capabilities).or_insert_with(Vec::new).push(name.clone());
+        extension_clauses.entry(&e.extensions).or_insert_with(Vec::new).push(name.clone());
+        let parameter_kinds: Vec<_> = e.parameters.iter().map(|p| p.kind.clone()).collect();
+        parameter_clauses.entry(parameter_kinds).or_insert_with(Vec::new).push(name.clone());
+    }
+
+    let capability_arms = capability_clauses.into_iter().map(|(k, v)| {
+        let capabilities = k.into_iter().map(|cap| as_ident(cap));
+        quote! {
+            #( #kind::#v )|* => &[#( Capability::#capabilities ),*]
+        }
+    });
+    let extension_arms = extension_clauses.into_iter().map(|(k, v)| {
+        let extensions = k.into_iter();
+        quote! {
+            #( #kind::#v )|* => &[#( #extensions ),*]
+        }
+    });
+    let parameter_arms = parameter_clauses.into_iter().map(|(kinds, v)| {
+        let parameter_kinds = kinds.iter().map(|k| as_ident(k));
+        quote! {
+            #( #kind::#v )|* => &[#( OperandKind::#parameter_kinds ),*]
+        }
+    });
+    let bit_name_list: Vec<_> = named_bits.iter().map(|(name, _)| name.clone()).collect();
+    let bit_array = quote! { [#(#kind::#bit_name_list),*] };
+
     quote! {
         bitflags! {
             #[doc = #comment]
@@ -61,6 +170,91 @@ fn gen_bit_enum_operand_kind(grammar: &structs::OperandKind) -> TokenStream {
                 #(#elements)*
             }
         }
+
+        impl #kind {
+            pub fn required_capabilities(self) -> Vec<Capability> {
+                let mut capabilities = vec![];
+                for bit in #bit_array.iter().cloned() {
+                    if self.contains(bit) {
+                        capabilities.extend_from_slice(#kind::bit_capabilities(bit));
+                    }
+                }
+                capabilities
+            }
+
+            pub fn required_extensions(self) -> Vec<&'static str> {
+                let mut extensions = vec![];
+                for bit in #bit_array.iter().cloned() {
+                    if self.contains(bit) {
+                        extensions.extend_from_slice(#kind::bit_extensions(bit));
+                    }
+                }
+                extensions
+            }
+
+            /// Returns the operand kinds of the extra parameters each set
+            /// bit pulls along when it appears in an instruction (e.g.
+            /// `LoopControl::DEPENDENCY_LENGTH` is followed by a
+            /// `LiteralInteger`).
+            pub fn parameters(self) -> Vec<OperandKind> {
+                let mut parameters = vec![];
+                for bit in #bit_array.iter().cloned() {
+                    if self.contains(bit) {
+                        parameters.extend_from_slice(#kind::bit_parameters(bit));
+                    }
+                }
+                parameters
+            }
+
+            fn bit_capabilities(bit: #kind) -> &'static [Capability] {
+                match bit {
+                    #(#capability_arms,)*
+                    _ => &[],
+                }
+            }
+
+            fn bit_extensions(bit: #kind) -> &'static [&'static str] {
+                match bit {
+                    #(#extension_arms,)*
+                    _ => &[],
+                }
+            }
+
+            fn bit_parameters(bit: #kind) -> &'static [OperandKind] {
+                match bit {
+                    #(#parameter_arms,)*
+                    _ => &[],
+                }
+            }
+        }
+
+        impl core::fmt::Display for #kind {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                if self.is_empty() {
+                    return write!(f, "{}", #none_symbol);
+                }
+                let mut first = true;
+                #(#display_checks)*
+                Ok(())
+            }
+        }
+
+        impl core::str::FromStr for #kind {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if s == #none_symbol {
+                    return Ok(#kind::empty());
+                }
+                let mut result = #kind::empty();
+                for part in s.split('|') {
+                    match part.trim() {
+                        #(#from_str_arms)*
+                        other => return Err(format!("unknown {} flag: {}", stringify!(#kind), other)),
+                    }
+                }
+                Ok(result)
+            }
+        }
     }
 }
 
@@ -76,12 +270,23 @@ fn gen_value_enum_operand_kind(grammar: &structs::OperandKind) -> TokenStream {
     let mut from_prim = vec![];
     let mut aliases = vec![];
     let mut capability_clauses = BTreeMap::new();
+    let mut extension_clauses = BTreeMap::new();
+    let mut first_version_clauses = BTreeMap::new();
+    let mut last_version_clauses = BTreeMap::new();
+    let mut parameter_clauses = BTreeMap::new();
+    let mut display_arms = vec![];
+    let mut from_str_arms = vec![];
     for e in &grammar.enumerants {
         if let Some(discriminator) = seen_discriminator.get(&e.value) {
             let symbol = as_ident(&e.symbol);
             aliases.push(quote! {
                 pub const #symbol: #kind = #kind::#discriminator;
             });
+
+            // Aliases parse back to the canonical discriminator, but are not
+            // themselves produced by `Display`.
+            let alias_text = e.symbol.to_string();
+            from_str_arms.push(quote! { #alias_text => Some(#kind::#discriminator) });
         } else {
             // Special case for Dim. Its enumerants can start with a digit.
             // So prefix with the kind name here.
@@ -98,7 +303,21 @@ fn gen_value_enum_operand_kind(grammar: &structs::OperandKind) -> TokenStream {
             enumerants.push(quote! { #name = #number });
             from_prim.push(quote! { #number => Some(#kind::#name) });
 
-            capability_clauses.entry(&e.capabilities).or_insert_with(Vec::new).push(name);
+            // The canonical symbol text, as it appears in SPIR-V assembly,
+            // not the (possibly `Dim`-prefixed) Rust identifier.
+            let symbol_text = e.symbol.to_string();
+            display_arms.push(quote! { #kind::#name => #symbol_text });
+            from_str_arms.push(quote! { #symbol_text => Some(#kind::#name) });
+
+            capability_clauses.entry(&e.capabilities).or_insert_with(Vec::new).push(name.clone());
+            extension_clauses.entry(&e.extensions).or_insert_with(Vec::new).push(name.clone());
+            first_version_clauses.entry(parse_version(e.version.as_ref())).or_insert_with(Vec::new).push(name.clone());
+            last_version_clauses.entry(parse_version(e.last_version.as_ref())).or_insert_with(Vec::new).push(name.clone());
+
+            // Enumerants like `Decoration::LinkageAttributes` pull along
+            // extra operands beyond the instruction's normal operand list.
+            let parameter_kinds: Vec<_> = e.parameters.iter().map(|p| p.kind.clone()).collect();
+            parameter_clauses.entry(parameter_kinds).or_insert_with(Vec::new).push(name);
         }
     }
 
@@ -110,6 +329,44 @@ fn gen_value_enum_operand_kind(grammar: &structs::OperandKind) -> TokenStream {
         }
     });
 
+    let extensions = extension_clauses.into_iter().map(|(k, v)| {
+        let kinds = std::iter::repeat(&kind);
+        let extensions = k.into_iter();
+        quote! {
+            #( #kinds::#v )|* => &[#( #extensions ),*]
+        }
+    });
+
+    let first_versions = first_version_clauses.into_iter().map(|(version, v)| {
+        let kinds = std::iter::repeat(&kind);
+        let version = match version {
+            Some((major, minor)) => quote! { Some((#major, #minor)) },
+            None => quote! { None },
+        };
+        quote! {
+            #( #kinds::#v )|* => #version
+        }
+    });
+
+    let last_versions = last_version_clauses.into_iter().map(|(version, v)| {
+        let kinds = std::iter::repeat(&kind);
+        let version = match version {
+            Some((major, minor)) => quote! { Some((#major, #minor)) },
+            None => quote! { None },
+        };
+        quote! {
+            #( #kinds::#v )|* => #version
+        }
+    });
+
+    let parameters = parameter_clauses.into_iter().map(|(kinds, v)| {
+        let kinds_repeat = std::iter::repeat(&kind);
+        let parameter_kinds = kinds.iter().map(|k| as_ident(k));
+        quote! {
+            #( #kinds_repeat::#v )|* => &[#( OperandKind::#parameter_kinds ),*]
+        }
+    });
+
     let comment = format!("/// SPIR-V operand kind: {}", get_spec_link(&grammar.kind));
     let attribute = value_enum_attribute();
 
@@ -129,6 +386,34 @@ fn gen_value_enum_operand_kind(grammar: &structs::OperandKind) -> TokenStream {
                     #(#capabilities),*
                 }
             }
+
+            pub fn required_extensions(self) -> &'static [&'static str] {
+                match self {
+                    #(#extensions),*
+                }
+            }
+
+            pub fn first_version(self) -> Option<(u8, u8)> {
+                match self {
+                    #(#first_versions),*
+                }
+            }
+
+            pub fn last_version(self) -> Option<(u8, u8)> {
+                match self {
+                    #(#last_versions),*
+                }
+            }
+
+            /// Returns the operand kinds of the extra parameters this
+            /// enumerant pulls along when it appears in an instruction
+            /// (e.g. `Decoration::LinkageAttributes` is followed by a
+            /// `LiteralString` and a `LinkageType`).
+            pub fn parameters(self) -> &'static [OperandKind] {
+                match self {
+                    #(#parameters),*
+                }
+            }
         }
 
         impl num_traits::FromPrimitive for #kind {
@@ -144,6 +429,26 @@ fn gen_value_enum_operand_kind(grammar: &structs::OperandKind) -> TokenStream {
                 Self::from_i64(n as i64)
             }
         }
+
+        impl core::fmt::Display for #kind {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let s = match *self {
+                    #(#display_arms),*
+                };
+                write!(f, "{}", s)
+            }
+        }
+
+        impl core::str::FromStr for #kind {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#from_str_arms,)*
+                    _ => None,
+                }
+                .ok_or_else(|| format!("unknown {} symbol: {}", stringify!(#kind), s))
+            }
+        }
     }
 }
 
@@ -169,6 +474,38 @@ pub fn gen_spirv_header(grammar: &structs::Grammar) -> TokenStream {
     // Operand kinds.
     let kinds = grammar.operand_kinds.iter().filter_map(gen_operand_kind);
 
+    // The classification of every operand kind named in the grammar (not
+    // just the `BitEnum`/`ValueEnum` ones), used to describe instruction
+    // operand signatures below.
+    let operand_kind_variants = grammar.operand_kinds.iter().map(|ok| as_ident(&ok.kind));
+
+    // Grammar-driven operand signature table: for every opcode, its ordered
+    // logical operands (kind, result-type/result-id/plain role, and
+    // quantifier), so generic code can walk an instruction's operands
+    // without a per-opcode match arm.
+    let operand_layouts = grammar.instructions.iter().map(|inst| {
+        let opname = as_ident(&inst.opname[2..]);
+        let logical_operands = inst.operands.iter().map(|operand| {
+            let kind = as_ident(&operand.kind);
+            let role = match operand.kind.as_str() {
+                "IdResultType" => quote! { OperandRole::ResultType },
+                "IdResult" => quote! { OperandRole::ResultId },
+                _ => quote! { OperandRole::Operand },
+            };
+            let quantifier = match operand.quantifier.as_deref().unwrap_or("") {
+                "?" => quote! { Quantifier::Optional },
+                "*" => quote! { Quantifier::Variadic },
+                _ => quote! { Quantifier::One },
+            };
+            quote! {
+                LogicalOperand { kind: OperandKind::#kind, role: #role, quantifier: #quantifier }
+            }
+        });
+        quote! {
+            Op::#opname => &[#(#logical_operands),*]
+        }
+    });
+
     // Opcodes.
     // Get the instruction table.
     let opcodes = grammar.instructions.iter().map(|inst| {
@@ -183,9 +520,63 @@ pub fn gen_spirv_header(grammar: &structs::Grammar) -> TokenStream {
         let opcode = inst.opcode;
         quote! { #opcode => Some(Op::#opname) }
     });
+    let display_arms = grammar.instructions.iter().map(|inst| {
+        let opname = as_ident(&inst.opname[2..]);
+        let symbol = inst.opname.clone();
+        quote! { Op::#opname => #symbol }
+    });
+    let from_str_arms = grammar.instructions.iter().map(|inst| {
+        let opname = as_ident(&inst.opname[2..]);
+        let symbol = inst.opname.clone();
+        quote! { #symbol => Some(Op::#opname) }
+    });
+
+    // Extension and version-range requirements, grouped the same way
+    // `capability_clauses` groups `required_capabilities()` for value enums.
+    let mut extension_clauses = std::collections::BTreeMap::new();
+    let mut first_version_clauses = std::collections::BTreeMap::new();
+    let mut last_version_clauses = std::collections::BTreeMap::new();
+    for inst in &grammar.instructions {
+        let opname = as_ident(&inst.opname[2..]);
+        extension_clauses.entry(&inst.extensions).or_insert_with(Vec::new).push(opname.clone());
+        first_version_clauses.entry(parse_version(inst.version.as_ref())).or_insert_with(Vec::new).push(opname.clone());
+        last_version_clauses.entry(parse_version(inst.last_version.as_ref())).or_insert_with(Vec::new).push(opname);
+    }
+
+    let required_extensions = extension_clauses.into_iter().map(|(k, v)| {
+        let extensions = k.into_iter();
+        quote! {
+            #( Op::#v )|* => &[#( #extensions ),*]
+        }
+    });
+
+    let first_versions = first_version_clauses.into_iter().map(|(version, v)| {
+        let version = match version {
+            Some((major, minor)) => quote! { Some((#major, #minor)) },
+            None => quote! { None },
+        };
+        quote! {
+            #( Op::#v )|* => #version
+        }
+    });
+
+    let last_versions = last_version_clauses.into_iter().map(|(version, v)| {
+        let version = match version {
+            Some((major, minor)) => quote! { Some((#major, #minor)) },
+            None => quote! { None },
+        };
+        quote! {
+            #( Op::#v )|* => #version
+        }
+    });
+
+    let spec_constant_op_opcodes = grammar.instructions.iter()
+        .filter(|inst| SPEC_CONSTANT_OP_WHITELIST.contains(&&inst.opname[2..]))
+        .map(|inst| as_ident(&inst.opname[2..]));
+
     let comment = format!("SPIR-V {} opcodes", get_spec_link("instructions"));
     let attribute = value_enum_attribute();
-    
+
     quote! {
         pub type Word = u32;
         pub const MAGIC_NUMBER: u32 = #magic_number;
@@ -194,13 +585,90 @@ pub fn gen_spirv_header(grammar: &structs::Grammar) -> TokenStream {
         pub const REVISION: u8 = #revision;
 
         #(#kinds)*
-        
+
+        /// The classification of an instruction operand, as named by the
+        /// SPIR-V grammar's `kind` field (covers both the `BitEnum`/
+        /// `ValueEnum` kinds above and the scalar/id/literal kinds that are
+        /// built into the grammar, e.g. `IdRef` or `LiteralString`).
+        #[repr(u16)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum OperandKind {
+            #(#operand_kind_variants),*
+        }
+
+        /// Whether a [`LogicalOperand`] is an instruction's result type,
+        /// its result id, or a regular operand.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum OperandRole {
+            ResultType,
+            ResultId,
+            Operand,
+        }
+
+        /// How many times a [`LogicalOperand`] occurs in an instruction's
+        /// encoded operand list, mirroring the grammar's `quantifier` field.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum Quantifier {
+            /// Exactly one.
+            One,
+            /// Zero or one (`?` in the grammar).
+            Optional,
+            /// Zero or more (`*` in the grammar).
+            Variadic,
+        }
+
+        /// One entry in an instruction's grammar-declared operand signature.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct LogicalOperand {
+            pub kind: OperandKind,
+            pub role: OperandRole,
+            pub quantifier: Quantifier,
+        }
+
+        /// Returns the ordered logical operand signature the grammar
+        /// declares for `op`, as a data-driven alternative to hand-matching
+        /// each opcode's operand layout.
+        pub fn operand_layout(op: Op) -> &'static [LogicalOperand] {
+            match op {
+                #(#operand_layouts),*
+            }
+        }
+
         #[doc = #comment]
         #attribute
         pub enum Op {
             #(#opcodes),*
         }
 
+        impl Op {
+            pub fn required_extensions(self) -> &'static [&'static str] {
+                match self {
+                    #(#required_extensions),*
+                }
+            }
+
+            pub fn first_version(self) -> Option<(u8, u8)> {
+                match self {
+                    #(#first_versions),*
+                }
+            }
+
+            pub fn last_version(self) -> Option<(u8, u8)> {
+                match self {
+                    #(#last_versions),*
+                }
+            }
+
+            /// Returns whether this opcode may be used as the wrapped
+            /// operation of an `OpSpecConstantOp` instruction.
+            pub fn is_valid_in_spec_constant_op(self) -> bool {
+                match self {
+                    #(Op::#spec_constant_op_opcodes)|* => true,
+                    _ => false,
+                }
+            }
+        }
+
         impl num_traits::FromPrimitive for Op {
             #[allow(trivial_numeric_casts)]
             fn from_i64(n: i64) -> Option<Self> {
@@ -214,6 +682,64 @@ pub fn gen_spirv_header(grammar: &structs::Grammar) -> TokenStream {
                 Self::from_i64(n as i64)
             }
         }
+
+        impl core::fmt::Display for Op {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let s = match *self {
+                    #(#display_arms),*
+                };
+                write!(f, "{}", s)
+            }
+        }
+
+        impl core::str::FromStr for Op {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#from_str_arms,)*
+                    _ => None,
+                }
+                .ok_or_else(|| format!("unknown opcode: {}", s))
+            }
+        }
+    }
+}
+
+/// Returns the generic `dr::Operand` parameter-count dispatch consumed by
+/// `dr::walk_operands()`. Every `BitEnum`/`ValueEnum` operand kind generated
+/// by `gen_spirv_header` above carries its own `parameters()` method (see
+/// `gen_bit_enum_operand_kind`/`gen_value_enum_operand_kind`); this just
+/// walks the same `grammar.operand_kinds` list once more and, for each such
+/// kind, emits a match arm that forwards to it. Because the match is
+/// generated from the grammar rather than hand-maintained, a newly
+/// parameterized kind (e.g. a future `ImageOperands` bit) gets an arm
+/// automatically instead of silently falling through a `_ => 0` default.
+///
+/// `dr::Operand` depends on `spirv`, not the other way around, so this
+/// dispatch is emitted into a `dr`-side generated file rather than into
+/// `gen_spirv_header`'s own output.
+pub fn gen_operand_parameter_counts(grammar: &structs::Grammar) -> TokenStream {
+    use structs::Category::*;
+    let arms = grammar.operand_kinds.iter()
+        .filter(|ok| matches!(ok.category, BitEnum | ValueEnum))
+        .map(|ok| {
+            let kind = as_ident(&ok.kind);
+            quote! { dr::Operand::#kind(v) => v.parameters().len() }
+        });
+
+    quote! {
+        /// Returns how many trailing operands belong to `operand` as enum
+        /// parameters rather than independent logical operands, e.g. a
+        /// `Decoration::LinkageAttributes` is followed by a `LiteralString`
+        /// and a `LinkageType`. Every enum kind has its own arm, which yields
+        /// `0` for enumerants that declare no parameters; scalar kinds like
+        /// `IdRef`/`LiteralInteger` fall through to the `0` default.
+        pub(crate) fn operand_parameter_count(operand: &dr::Operand) -> usize {
+            match operand {
+                #(#arms,)*
+                _ => 0,
+            }
+        }
     }
 }
 
@@ -232,6 +758,16 @@ pub fn gen_glsl_std_450_opcodes(grammar: &structs::ExtInstSetGrammar) -> TokenSt
         let opcode = inst.opcode;
         quote! { #opcode => Some(GLOp::#opname) }
     });
+    let display_arms = grammar.instructions.iter().map(|inst| {
+        let opname = as_ident(&inst.opname);
+        let symbol = inst.opname.clone();
+        quote! { GLOp::#opname => #symbol }
+    });
+    let from_str_arms = grammar.instructions.iter().map(|inst| {
+        let opname = as_ident(&inst.opname);
+        let symbol = inst.opname.clone();
+        quote! { #symbol => Some(GLOp::#opname) }
+    });
 
     let comment = format!("[GLSL.std.450]({}) extended instruction opcode", GLSL_STD_450_SPEC_LINK);
     let attribute = value_enum_attribute();
@@ -256,6 +792,26 @@ pub fn gen_glsl_std_450_opcodes(grammar: &structs::ExtInstSetGrammar) -> TokenSt
                 Self::from_i64(n as i64)
             }
         }
+
+        impl core::fmt::Display for GLOp {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let s = match *self {
+                    #(#display_arms),*
+                };
+                write!(f, "{}", s)
+            }
+        }
+
+        impl core::str::FromStr for GLOp {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#from_str_arms,)*
+                    _ => None,
+                }
+                .ok_or_else(|| format!("unknown GLSL.std.450 opcode: {}", s))
+            }
+        }
     }
 }
 
@@ -274,6 +830,16 @@ pub fn gen_opencl_std_opcodes(grammar: &structs::ExtInstSetGrammar) -> TokenStre
         let opcode = inst.opcode;
         quote! { #opcode => Some(CLOp::#opname) }
     });
+    let display_arms = grammar.instructions.iter().map(|inst| {
+        let opname = as_ident(&inst.opname);
+        let symbol = inst.opname.clone();
+        quote! { CLOp::#opname => #symbol }
+    });
+    let from_str_arms = grammar.instructions.iter().map(|inst| {
+        let opname = as_ident(&inst.opname);
+        let symbol = inst.opname.clone();
+        quote! { #symbol => Some(CLOp::#opname) }
+    });
 
     let comment = format!("[OpenCL.std]({}) extended instruction opcode", OPENCL_STD_SPEC_LINK);
     let attribute = value_enum_attribute();
@@ -298,5 +864,25 @@ pub fn gen_opencl_std_opcodes(grammar: &structs::ExtInstSetGrammar) -> TokenStre
                 Self::from_i64(n as i64)
             }
         }
+
+        impl core::fmt::Display for CLOp {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let s = match *self {
+                    #(#display_arms),*
+                };
+                write!(f, "{}", s)
+            }
+        }
+
+        impl core::str::FromStr for CLOp {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#from_str_arms,)*
+                    _ => None,
+                }
+                .ok_or_else(|| format!("unknown OpenCL.std opcode: {}", s))
+            }
+        }
     }
 }
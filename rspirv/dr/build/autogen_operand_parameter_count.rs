@@ -0,0 +1,44 @@
+// AUTOMATICALLY GENERATED from the SPIR-V JSON grammar:
+//   external/spirv.core.grammar.json.
+// DO NOT MODIFY!
+
+/// Returns how many trailing operands belong to `operand` as enum
+/// parameters rather than independent logical operands, e.g. a
+/// `Decoration::LinkageAttributes` is followed by a `LiteralString`
+/// and a `LinkageType`. Every enum kind has its own arm, which yields
+/// `0` for enumerants that declare no parameters; scalar kinds like
+/// `IdRef`/`LiteralInteger` fall through to the `0` default.
+pub(crate) fn operand_parameter_count(operand: &dr::Operand) -> usize {
+    match operand {
+        dr::Operand::SourceLanguage(v) => v.parameters().len(),
+        dr::Operand::ExecutionModel(v) => v.parameters().len(),
+        dr::Operand::AddressingModel(v) => v.parameters().len(),
+        dr::Operand::MemoryModel(v) => v.parameters().len(),
+        dr::Operand::ExecutionMode(v) => v.parameters().len(),
+        dr::Operand::StorageClass(v) => v.parameters().len(),
+        dr::Operand::Dim(v) => v.parameters().len(),
+        dr::Operand::SamplerAddressingMode(v) => v.parameters().len(),
+        dr::Operand::SamplerFilterMode(v) => v.parameters().len(),
+        dr::Operand::ImageFormat(v) => v.parameters().len(),
+        dr::Operand::ImageChannelOrder(v) => v.parameters().len(),
+        dr::Operand::ImageChannelDataType(v) => v.parameters().len(),
+        dr::Operand::ImageOperands(v) => v.parameters().len(),
+        dr::Operand::FPFastMathMode(v) => v.parameters().len(),
+        dr::Operand::FPRoundingMode(v) => v.parameters().len(),
+        dr::Operand::LinkageType(v) => v.parameters().len(),
+        dr::Operand::AccessQualifier(v) => v.parameters().len(),
+        dr::Operand::FunctionParameterAttribute(v) => v.parameters().len(),
+        dr::Operand::Decoration(v) => v.parameters().len(),
+        dr::Operand::BuiltIn(v) => v.parameters().len(),
+        dr::Operand::SelectionControl(v) => v.parameters().len(),
+        dr::Operand::LoopControl(v) => v.parameters().len(),
+        dr::Operand::FunctionControl(v) => v.parameters().len(),
+        dr::Operand::MemoryAccess(v) => v.parameters().len(),
+        dr::Operand::Scope(v) => v.parameters().len(),
+        dr::Operand::GroupOperation(v) => v.parameters().len(),
+        dr::Operand::KernelEnqueueFlags(v) => v.parameters().len(),
+        dr::Operand::KernelProfilingInfo(v) => v.parameters().len(),
+        dr::Operand::Capability(v) => v.parameters().len(),
+        _ => 0,
+    }
+}
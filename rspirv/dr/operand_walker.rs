@@ -0,0 +1,83 @@
+// Copyright 2017 Google Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::dr;
+use crate::spirv;
+
+// Generated by `autogen::gen_operand_parameter_counts` from the SPIR-V
+// grammar: a match over every `BitEnum`/`ValueEnum` operand kind, each arm
+// forwarding to that kind's own `parameters()` method. Kept generated
+// (rather than hand-matching a subset of kinds here) so a newly
+// parameterized kind automatically gets an arm instead of silently falling
+// through to the `0` default.
+include!("build/autogen_operand_parameter_count.rs");
+
+/// One resolved logical operand of a walked instruction: the grammar entry
+/// describing its kind/role/quantifier, together with the decoded
+/// [`dr::Operand`] value(s) that fill it. `values` holds more than one
+/// element when `logical.quantifier` is [`spirv::Quantifier::Variadic`], or
+/// when the first value is a parameterized enum operand (e.g. `Decoration`,
+/// `ExecutionMode`, `MemoryAccess`, `ImageOperands`) that pulls along trailing
+/// parameters, per that enum's own `parameters()` method.
+pub struct WalkedOperand<'a> {
+    pub logical: &'static spirv::LogicalOperand,
+    pub values: &'a [dr::Operand],
+}
+
+/// Walks `inst`'s operands according to the grammar-declared operand
+/// signature returned by `spirv::operand_layout(inst.class.opcode)`,
+/// expanding `?`/`*` quantifiers and enum operands that pull along trailing
+/// parameters (e.g. `Decoration::LinkageAttributes`, `ExecutionMode::LocalSize`).
+/// The layout's result-type and result-id entries are skipped, since those
+/// are held in `inst.result_type`/`inst.result_id` rather than `inst.operands`.
+///
+/// This lets callers such as a disassembler, a structural validator, or a
+/// generic operand rewriter interpret an instruction's operands without a
+/// per-opcode match arm.
+///
+/// `inst.operands` may legitimately be shorter than the layout expects (a
+/// truncated or otherwise malformed module); the walk simply stops early in
+/// that case rather than panicking, since producing a partial walk over
+/// malformed input is exactly what a validator needs.
+pub fn walk_operands(inst: &dr::Instruction) -> Vec<WalkedOperand<'_>> {
+    let layout = spirv::operand_layout(inst.class.opcode);
+    let mut walked = Vec::with_capacity(layout.len());
+    let len = inst.operands.len();
+    let mut index = 0;
+
+    for logical in layout {
+        if logical.role != spirv::OperandRole::Operand {
+            continue;
+        }
+        if index >= len {
+            break;
+        }
+        match logical.quantifier {
+            spirv::Quantifier::One | spirv::Quantifier::Optional => {
+                let end = (index + 1 + operand_parameter_count(&inst.operands[index])).min(len);
+                walked.push(WalkedOperand { logical, values: &inst.operands[index..end] });
+                index = end;
+            }
+            spirv::Quantifier::Variadic => {
+                let start = index;
+                while index < len {
+                    index = (index + 1 + operand_parameter_count(&inst.operands[index])).min(len);
+                }
+                walked.push(WalkedOperand { logical, values: &inst.operands[start..index] });
+            }
+        }
+    }
+
+    walked
+}
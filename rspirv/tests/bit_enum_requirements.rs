@@ -0,0 +1,37 @@
+// Copyright 2017 Google Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rspirv::spirv;
+
+#[test]
+fn function_control_inline_requires_no_capability() {
+    // `FunctionControl` bits are all core, unlike e.g. some `LoopControl`
+    // bits; the method should still resolve to an (empty) static table
+    // rather than panicking or matching nothing.
+    assert!(spirv::FunctionControl::INLINE
+        .required_capabilities()
+        .is_empty());
+}
+
+#[test]
+fn bit_enum_requirements_union_across_every_set_bit() {
+    let mask = spirv::FunctionControl::INLINE | spirv::FunctionControl::PURE;
+    let capabilities = mask.required_capabilities();
+    // Whatever each bit individually requires must show up in the union.
+    for bit in [spirv::FunctionControl::INLINE, spirv::FunctionControl::PURE] {
+        for capability in bit.required_capabilities() {
+            assert!(capabilities.contains(&capability));
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright 2017 Google Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rspirv::spirv;
+
+#[test]
+fn value_enum_round_trips_through_display_and_from_str() {
+    assert_eq!(spirv::Decoration::ArrayStride.to_string(), "ArrayStride");
+    assert_eq!(
+        "ArrayStride".parse::<spirv::Decoration>().unwrap(),
+        spirv::Decoration::ArrayStride
+    );
+}
+
+#[test]
+fn dim_displays_the_grammar_symbol_not_the_rust_identifier() {
+    // `Dim::Dim1D` is the Rust identifier (digits can't start an ident), but
+    // the SPIR-V assembly symbol is just "1D".
+    assert_eq!(spirv::Dim::Dim1D.to_string(), "1D");
+    assert_eq!("1D".parse::<spirv::Dim>().unwrap(), spirv::Dim::Dim1D);
+}
+
+#[test]
+fn bit_enum_mask_joins_set_bits_in_definition_order() {
+    let mask = spirv::MemoryAccess::VOLATILE | spirv::MemoryAccess::ALIGNED;
+    assert_eq!(mask.to_string(), "Volatile|Aligned");
+    assert_eq!(
+        "Volatile|Aligned".parse::<spirv::MemoryAccess>().unwrap(),
+        mask
+    );
+}
+
+#[test]
+fn bit_enum_empty_mask_displays_and_parses_as_none() {
+    assert_eq!(spirv::MemoryAccess::empty().to_string(), "None");
+    assert_eq!(
+        "None".parse::<spirv::MemoryAccess>().unwrap(),
+        spirv::MemoryAccess::empty()
+    );
+}
+
+#[test]
+fn op_round_trips_through_display_and_from_str() {
+    assert_eq!(spirv::Op::IAdd.to_string(), "OpIAdd");
+    assert_eq!("OpIAdd".parse::<spirv::Op>().unwrap(), spirv::Op::IAdd);
+}
@@ -0,0 +1,31 @@
+// Copyright 2017 Google Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rspirv::spirv;
+
+#[test]
+fn core_capability_requires_no_extension() {
+    assert!(spirv::Capability::Shader.required_extensions().is_empty());
+}
+
+#[test]
+fn core_opcode_requires_no_extension() {
+    assert!(spirv::Op::Nop.required_extensions().is_empty());
+}
+
+#[test]
+fn core_opcode_has_been_available_since_1_0_and_was_never_removed() {
+    assert_eq!(spirv::Op::Nop.first_version(), Some((1, 0)));
+    assert_eq!(spirv::Op::Nop.last_version(), None);
+}
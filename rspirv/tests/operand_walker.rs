@@ -0,0 +1,104 @@
+// Copyright 2017 Google Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rspirv::dr;
+use rspirv::spirv;
+
+#[test]
+fn walk_operands_expands_decorate_parameters() {
+    let inst = dr::Instruction::new(
+        spirv::Op::Decorate,
+        None,
+        None,
+        vec![
+            dr::Operand::IdRef(1),
+            dr::Operand::Decoration(spirv::Decoration::ArrayStride),
+            dr::Operand::LiteralInt32(16),
+        ],
+    );
+    let walked = dr::walk_operands(&inst);
+
+    assert_eq!(walked.len(), 2);
+    assert_eq!(walked[0].values.len(), 1); // the target IdRef
+    assert_eq!(walked[1].values.len(), 2); // Decoration + its LiteralInteger parameter
+}
+
+#[test]
+fn walk_operands_expands_execution_mode_parameters() {
+    // `OpExecutionMode`'s grammar operand list is just `[IdRef,
+    // ExecutionMode]`; the LocalSize x/y/z literals only exist because
+    // `ExecutionMode::LocalSize` itself declares them as parameters.
+    let inst = dr::Instruction::new(
+        spirv::Op::ExecutionMode,
+        None,
+        None,
+        vec![
+            dr::Operand::IdRef(1),
+            dr::Operand::ExecutionMode(spirv::ExecutionMode::LocalSize),
+            dr::Operand::LiteralInt32(8),
+            dr::Operand::LiteralInt32(8),
+            dr::Operand::LiteralInt32(1),
+        ],
+    );
+    let walked = dr::walk_operands(&inst);
+
+    assert_eq!(walked.len(), 2);
+    assert_eq!(walked[1].values.len(), 4); // ExecutionMode + 3 LiteralInteger parameters
+}
+
+#[test]
+fn walk_operands_expands_memory_access_parameters() {
+    // `MemoryAccess::ALIGNED` pulls a `LiteralInteger` alignment, just like
+    // the enum-valued operands above, but `MemoryAccess` is a `BitEnum`
+    // rather than a `ValueEnum`, so this exercises the bit-enum side of
+    // parameter dispatch (the hardcoded match this replaced only covered
+    // `ValueEnum` kinds and silently dropped this alignment literal).
+    let inst = dr::Instruction::new(
+        spirv::Op::Load,
+        Some(1),
+        Some(2),
+        vec![
+            dr::Operand::IdRef(3),
+            dr::Operand::MemoryAccess(spirv::MemoryAccess::ALIGNED),
+            dr::Operand::LiteralInt32(4),
+        ],
+    );
+    let walked = dr::walk_operands(&inst);
+
+    let memory_access = walked
+        .iter()
+        .find(|w| w.logical.kind == spirv::OperandKind::MemoryAccess)
+        .expect("OpLoad declares a MemoryAccess logical operand");
+    assert_eq!(memory_access.values.len(), 2); // MemoryAccess + its LiteralInteger alignment
+}
+
+#[test]
+fn walk_operands_does_not_panic_on_truncated_operands() {
+    // A malformed/truncated module: `LocalSize` is missing its x/y/z
+    // parameters. The walker must stop gracefully instead of slicing out of
+    // bounds, since validating exactly this kind of input is the point.
+    let inst = dr::Instruction::new(
+        spirv::Op::ExecutionMode,
+        None,
+        None,
+        vec![
+            dr::Operand::IdRef(1),
+            dr::Operand::ExecutionMode(spirv::ExecutionMode::LocalSize),
+        ],
+    );
+    let walked = dr::walk_operands(&inst);
+
+    assert_eq!(walked.len(), 2);
+    assert_eq!(walked[1].values.len(), 1);
+}
@@ -0,0 +1,37 @@
+// Copyright 2017 Google Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rspirv::spirv;
+
+#[test]
+fn arithmetic_and_conversion_opcodes_are_valid_in_spec_constant_op() {
+    assert!(spirv::Op::IAdd.is_valid_in_spec_constant_op());
+    assert!(spirv::Op::Bitcast.is_valid_in_spec_constant_op());
+    assert!(spirv::Op::AccessChain.is_valid_in_spec_constant_op());
+}
+
+#[test]
+fn kernel_pointer_casts_are_valid_in_spec_constant_op() {
+    // Distinct from the AccessChain family above: the spec's
+    // "Specialization" section separately lists the Kernel-capability
+    // pointer casts as legal OpSpecConstantOp operations.
+    assert!(spirv::Op::GenericCastToPtr.is_valid_in_spec_constant_op());
+    assert!(spirv::Op::PtrCastToGeneric.is_valid_in_spec_constant_op());
+}
+
+#[test]
+fn opcodes_outside_the_spec_constant_op_whitelist_are_rejected() {
+    assert!(!spirv::Op::FunctionCall.is_valid_in_spec_constant_op());
+    assert!(!spirv::Op::Load.is_valid_in_spec_constant_op());
+}